@@ -0,0 +1,135 @@
+//! Sound effect and music playback.
+//!
+
+use crate::sys;
+use std::ffi;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// `sys::Music` carries raw pointers owned exclusively by raylib's single
+/// audio thread; kioto only ever touches them from the main loop, so it is
+/// safe to hand a copy across this registry's `Mutex`.
+struct MusicHandle(sys::Music);
+
+unsafe impl Send for MusicHandle {}
+
+static ACTIVE_MUSIC: Mutex<Vec<MusicHandle>> = Mutex::new(Vec::new());
+
+/// Calls `UpdateMusicStream` for every currently-loaded `Music`. Wired into
+/// the `begin_frame`/`end_frame` region of the run loop when audio is
+/// enabled.
+pub(crate) fn update_streams() {
+    for handle in ACTIVE_MUSIC.lock().unwrap().iter() {
+        unsafe {
+            sys::update_music_stream(handle.0);
+        }
+    }
+}
+
+fn load_path<P: AsRef<Path>>(path: P) -> io::Result<ffi::CString> {
+    let path = path.as_ref().to_str().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "Audio path is not valid UTF-8")
+    })?;
+
+    Ok(ffi::CString::new(path)?)
+}
+
+/// A short sound effect, fully loaded into memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::audio;
+///
+/// let sound = audio::Sound::load("assets/jump.wav").unwrap();
+/// sound.play();
+/// ```
+pub struct Sound {
+    raw: sys::Sound,
+}
+
+impl Sound {
+    /// Loads a short sound effect from disk.
+    pub fn load<P>(path: P) -> io::Result<Sound>
+    where
+        P: AsRef<Path>,
+    {
+        let path = load_path(path)?;
+        let raw = unsafe { sys::load_sound(path.as_ptr()) };
+
+        if raw.frame_count == 0 {
+            return Err(io::Error::new(io::ErrorKind::Other, "Unable to load sound"));
+        }
+
+        Ok(Sound { raw })
+    }
+
+    /// Plays the sound once.
+    pub fn play(&self) {
+        unsafe {
+            sys::play_sound(self.raw);
+        }
+    }
+}
+
+impl Drop for Sound {
+    fn drop(&mut self) {
+        unsafe {
+            sys::unload_sound(self.raw);
+        }
+    }
+}
+
+/// A longer, streamed music track.
+///
+/// Once playing, the track advances each frame while `Context::run` or
+/// `Runtime::run_with` is driving the loop with audio enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::audio;
+///
+/// let music = audio::Music::stream("assets/theme.ogg").unwrap();
+/// music.play();
+/// ```
+pub struct Music {
+    raw: sys::Music,
+}
+
+impl Music {
+    /// Opens a music file from disk for streamed playback.
+    pub fn stream<P>(path: P) -> io::Result<Music>
+    where
+        P: AsRef<Path>,
+    {
+        let path = load_path(path)?;
+        let raw = unsafe { sys::load_music_stream(path.as_ptr()) };
+
+        if raw.frame_count == 0 {
+            return Err(io::Error::new(io::ErrorKind::Other, "Unable to load music"));
+        }
+
+        ACTIVE_MUSIC.lock().unwrap().push(MusicHandle(raw));
+
+        Ok(Music { raw })
+    }
+
+    /// Starts (or restarts) playback of the music stream.
+    pub fn play(&self) {
+        unsafe {
+            sys::play_music_stream(self.raw);
+        }
+    }
+}
+
+impl Drop for Music {
+    fn drop(&mut self) {
+        ACTIVE_MUSIC.lock().unwrap().retain(|handle| handle.0 != self.raw);
+
+        unsafe {
+            sys::unload_music_stream(self.raw);
+        }
+    }
+}
@@ -1,15 +1,37 @@
+use crate::audio;
+use crate::input::Key;
 use crate::sys;
+use crate::terminal;
+use crate::video::{self, Backend};
+use std::collections::HashSet;
 use std::ffi;
 use std::io;
+use std::time::Instant;
+
+/// The default number of fixed updates per second.
+const DEFAULT_TARGET_FPS: u32 = 60;
+
+/// The largest real frame time that is fed into the accumulator, to avoid
+/// the "spiral of death" where a long stall forces an ever-growing number
+/// of catch-up updates.
+const MAX_FRAME_TIME: f32 = 0.25;
 
 /// A trait defining event callbacks.
 ///
 pub trait Delegate {
-    ///
-    fn update(&mut self, context: &mut Context) {}
+    /// Called zero or more times per frame, once for each fixed step of
+    /// `dt` seconds that the elapsed real time has accumulated.
+    fn update(&mut self, context: &mut Context, dt: f32) {}
 
-    ///
-    fn render(&mut self, context: &mut Context) {}
+    /// Called once per frame. `alpha` is how far between the last and next
+    /// fixed update the frame falls (0.0-1.0), for interpolating state.
+    fn render(&mut self, context: &mut Context, alpha: f32) {}
+
+    /// Called once for each key that became pressed down this frame.
+    fn key_down(&mut self, context: &mut Context, key: Key) {}
+
+    /// Called once for each key that was released this frame.
+    fn key_up(&mut self, context: &mut Context, key: Key) {}
 }
 
 /// Builds a Context with custom configuration values.
@@ -29,6 +51,9 @@ pub trait Delegate {
 pub struct Builder {
     title: String,
     enable_video: bool,
+    enable_audio: bool,
+    backend: Backend,
+    target_fps: u32,
 }
 
 impl Builder {
@@ -47,6 +72,9 @@ impl Builder {
         Self {
             title: "".to_string(),
             enable_video: false,
+            enable_audio: false,
+            backend: Backend::Window,
+            target_fps: DEFAULT_TARGET_FPS,
         }
     }
 
@@ -91,6 +119,68 @@ impl Builder {
         self
     }
 
+    /// Enable the audio driver.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kioto::context;
+    ///
+    /// fn main() {
+    ///     let mut context = context::Builder::new()
+    ///         .enable_audio()
+    ///         .build()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn enable_audio(&mut self) -> &mut Builder {
+        self.enable_audio = true;
+        self
+    }
+
+    /// Selects which rendering backend the video driver uses.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kioto::context;
+    /// use kioto::video::Backend;
+    ///
+    /// fn main() {
+    ///     let mut context = context::Builder::new()
+    ///         .enable_video()
+    ///         .backend(Backend::Terminal)
+    ///         .build()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn backend(&mut self, backend: Backend) -> &mut Builder {
+        self.backend = backend;
+        self
+    }
+
+    /// Sets the number of fixed `Delegate::update` calls per second.
+    ///
+    /// A `target_fps` of `0` would make the fixed timestep infinite, so it
+    /// is clamped to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kioto::context;
+    ///
+    /// fn main() {
+    ///     let mut context = context::Builder::new()
+    ///         .target_fps(30)
+    ///         .build()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn target_fps(&mut self, target_fps: u32) -> &mut Builder {
+        self.target_fps = target_fps.max(1);
+        self
+    }
+
     /// Build a new context
     ///
     /// # Examples
@@ -105,21 +195,48 @@ impl Builder {
     /// }
     pub fn build(&mut self) -> io::Result<Context> {
         if self.enable_video {
-            let title = ffi::CString::new(self.title.clone())?;
-            let is_ready = unsafe {
-                sys::init_video(0, 0, title.as_ptr());
-                sys::is_video_ready()
-            };
+            match self.backend {
+                Backend::Window => {
+                    let title = ffi::CString::new(self.title.clone())?;
+                    let is_ready = unsafe {
+                        sys::init_video(0, 0, title.as_ptr());
+                        sys::is_video_ready()
+                    };
+
+                    if !is_ready {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "Unable to initialize video driver",
+                        ));
+                    }
+                }
+                Backend::Terminal => {
+                    video::init_terminal_backend(terminal::DEFAULT_WIDTH, terminal::DEFAULT_HEIGHT);
+                }
+            }
+        }
 
-            if !is_ready {
+        if self.enable_audio {
+            unsafe {
+                sys::init_audio();
+            }
+
+            if !unsafe { sys::is_audio_ready() } {
                 return Err(io::Error::new(
                     io::ErrorKind::Other,
-                    "Unable to initialize video driver",
+                    "Unable to initialize audio driver",
                 ));
             }
         }
 
-        Ok(Context { running: false })
+        Ok(Context {
+            running: false,
+            enable_audio: self.enable_audio,
+            keys_down: HashSet::new(),
+            fixed_dt: 1.0 / self.target_fps as f32,
+            accumulator: 0.0,
+            last_tick: None,
+        })
     }
 }
 
@@ -136,6 +253,11 @@ impl Builder {
 /// ```
 pub struct Context {
     running: bool,
+    enable_audio: bool,
+    keys_down: HashSet<Key>,
+    fixed_dt: f32,
+    accumulator: f32,
+    last_tick: Option<Instant>,
 }
 
 impl Context {
@@ -151,27 +273,57 @@ impl Context {
     /// }
     /// ```
     pub fn new() -> Self {
-        Self { running: false }
+        Self {
+            running: false,
+            enable_audio: false,
+            keys_down: HashSet::new(),
+            fixed_dt: 1.0 / DEFAULT_TARGET_FPS as f32,
+            accumulator: 0.0,
+            last_tick: None,
+        }
     }
 
-    ///
+    /// Runs the context loop, calling `Delegate::update` at a fixed
+    /// timestep and `Delegate::render` once per frame.
     ///
     pub fn run<D>(&mut self, delegate: &mut D)
     where
         D: Delegate,
     {
         self.running = true;
+        self.last_tick = Some(Instant::now());
+
         while self.running {
-            unsafe {
-                sys::begin_frame();
+            video::begin_frame();
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(self.last_tick.unwrap_or(now)).as_secs_f32();
+            self.last_tick = Some(now);
+            self.accumulator += elapsed.min(MAX_FRAME_TIME);
+
+            if self.enable_audio {
+                audio::update_streams();
             }
 
-            delegate.update(self);
-            delegate.render(self);
+            while self.accumulator >= self.fixed_dt {
+                delegate.update(self, self.fixed_dt);
+                self.accumulator -= self.fixed_dt;
+            }
 
-            unsafe {
-                sys::end_frame();
+            let alpha = self.accumulator / self.fixed_dt;
+            delegate.render(self, alpha);
+
+            for &key in Key::ALL {
+                let is_down = unsafe { sys::is_key_down(key.code()) };
+
+                if is_down && self.keys_down.insert(key) {
+                    delegate.key_down(self, key);
+                } else if !is_down && self.keys_down.remove(&key) {
+                    delegate.key_up(self, key);
+                }
             }
+
+            video::end_frame();
         }
     }
 
@@ -188,7 +340,13 @@ impl Drop for Context {
             if sys::is_video_ready() {
                 sys::close_video();
             }
+
+            if sys::is_audio_ready() {
+                sys::close_audio();
+            }
         }
+
+        video::shutdown_backend();
     }
 }
 
@@ -0,0 +1,343 @@
+//! Keyboard input polling and key enumeration.
+//!
+
+use crate::sys;
+use std::os::raw::c_int;
+
+/// A keyboard key, mapping to the underlying raylib keycode.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::input;
+///
+/// if input::is_down(input::Key::Space) {
+///     // jump
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    Space,
+    Apostrophe,
+    Comma,
+    Minus,
+    Period,
+    Slash,
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Semicolon,
+    Equal,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Escape,
+    Enter,
+    Tab,
+    Backspace,
+    Right,
+    Left,
+    Down,
+    Up,
+    LeftShift,
+    LeftControl,
+    LeftAlt,
+    RightShift,
+    RightControl,
+    RightAlt,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+impl Key {
+    /// All keys recognized by this enum, in declaration order.
+    pub const ALL: &'static [Key] = &[
+        Key::Space,
+        Key::Apostrophe,
+        Key::Comma,
+        Key::Minus,
+        Key::Period,
+        Key::Slash,
+        Key::Zero,
+        Key::One,
+        Key::Two,
+        Key::Three,
+        Key::Four,
+        Key::Five,
+        Key::Six,
+        Key::Seven,
+        Key::Eight,
+        Key::Nine,
+        Key::Semicolon,
+        Key::Equal,
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+        Key::G,
+        Key::H,
+        Key::I,
+        Key::J,
+        Key::K,
+        Key::L,
+        Key::M,
+        Key::N,
+        Key::O,
+        Key::P,
+        Key::Q,
+        Key::R,
+        Key::S,
+        Key::T,
+        Key::U,
+        Key::V,
+        Key::W,
+        Key::X,
+        Key::Y,
+        Key::Z,
+        Key::Escape,
+        Key::Enter,
+        Key::Tab,
+        Key::Backspace,
+        Key::Right,
+        Key::Left,
+        Key::Down,
+        Key::Up,
+        Key::LeftShift,
+        Key::LeftControl,
+        Key::LeftAlt,
+        Key::RightShift,
+        Key::RightControl,
+        Key::RightAlt,
+        Key::F1,
+        Key::F2,
+        Key::F3,
+        Key::F4,
+        Key::F5,
+        Key::F6,
+        Key::F7,
+        Key::F8,
+        Key::F9,
+        Key::F10,
+        Key::F11,
+        Key::F12,
+    ];
+
+    /// The raylib keycode this key maps to.
+    pub fn code(self) -> c_int {
+        match self {
+            Key::Space => 32,
+            Key::Apostrophe => 39,
+            Key::Comma => 44,
+            Key::Minus => 45,
+            Key::Period => 46,
+            Key::Slash => 47,
+            Key::Zero => 48,
+            Key::One => 49,
+            Key::Two => 50,
+            Key::Three => 51,
+            Key::Four => 52,
+            Key::Five => 53,
+            Key::Six => 54,
+            Key::Seven => 55,
+            Key::Eight => 56,
+            Key::Nine => 57,
+            Key::Semicolon => 59,
+            Key::Equal => 61,
+            Key::A => 65,
+            Key::B => 66,
+            Key::C => 67,
+            Key::D => 68,
+            Key::E => 69,
+            Key::F => 70,
+            Key::G => 71,
+            Key::H => 72,
+            Key::I => 73,
+            Key::J => 74,
+            Key::K => 75,
+            Key::L => 76,
+            Key::M => 77,
+            Key::N => 78,
+            Key::O => 79,
+            Key::P => 80,
+            Key::Q => 81,
+            Key::R => 82,
+            Key::S => 83,
+            Key::T => 84,
+            Key::U => 85,
+            Key::V => 86,
+            Key::W => 87,
+            Key::X => 88,
+            Key::Y => 89,
+            Key::Z => 90,
+            Key::Escape => 256,
+            Key::Enter => 257,
+            Key::Tab => 258,
+            Key::Backspace => 259,
+            Key::Right => 262,
+            Key::Left => 263,
+            Key::Down => 264,
+            Key::Up => 265,
+            Key::LeftShift => 340,
+            Key::LeftControl => 341,
+            Key::LeftAlt => 342,
+            Key::RightShift => 344,
+            Key::RightControl => 345,
+            Key::RightAlt => 346,
+            Key::F1 => 290,
+            Key::F2 => 291,
+            Key::F3 => 292,
+            Key::F4 => 293,
+            Key::F5 => 294,
+            Key::F6 => 295,
+            Key::F7 => 296,
+            Key::F8 => 297,
+            Key::F9 => 298,
+            Key::F10 => 299,
+            Key::F11 => 300,
+            Key::F12 => 301,
+        }
+    }
+
+    /// Looks up the key matching a raw raylib keycode, if any is known.
+    pub fn from_code(code: c_int) -> Option<Key> {
+        Key::ALL.iter().copied().find(|key| key.code() == code)
+    }
+}
+
+/// Returns true if the given key was pressed during this frame.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::input;
+///
+/// if input::is_pressed(input::Key::Enter) {
+///     // confirm
+/// }
+/// ```
+pub fn is_pressed(key: Key) -> bool {
+    unsafe { sys::is_key_pressed(key.code()) }
+}
+
+/// Returns true if the given key was released during this frame.
+pub fn is_released(key: Key) -> bool {
+    unsafe { sys::is_key_released(key.code()) }
+}
+
+/// Returns true if the given key is currently held down.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::input;
+///
+/// if input::is_down(input::Key::Left) {
+///     // move left
+/// }
+/// ```
+pub fn is_down(key: Key) -> bool {
+    unsafe { sys::is_key_down(key.code()) }
+}
+
+/// Returns true if the given key is currently up.
+pub fn is_up(key: Key) -> bool {
+    unsafe { sys::is_key_up(key.code()) }
+}
+
+/// An iterator draining the queue of keys pressed this frame, in press order.
+///
+/// Unrecognized keycodes are skipped rather than ending the iteration early.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::input;
+///
+/// for key in input::pressed_keys() {
+///     println!("{:?}", key);
+/// }
+/// ```
+pub struct PressedKeys;
+
+impl Iterator for PressedKeys {
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Key> {
+        loop {
+            let code = unsafe { sys::get_next_key() };
+
+            if code == 0 {
+                return None;
+            }
+
+            if let Some(key) = Key::from_code(code) {
+                return Some(key);
+            }
+        }
+    }
+}
+
+/// Returns an iterator over the keys newly pressed this frame.
+pub fn pressed_keys() -> PressedKeys {
+    PressedKeys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn code_round_trips_through_from_code_for_every_key() {
+        for &key in Key::ALL {
+            assert_eq!(Key::from_code(key.code()), Some(key));
+        }
+    }
+
+    #[test]
+    pub fn from_code_rejects_unknown_codes() {
+        assert_eq!(Key::from_code(-1), None);
+        assert_eq!(Key::from_code(0), None);
+    }
+}
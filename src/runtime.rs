@@ -1,6 +1,11 @@
+use crate::audio;
 use crate::sys;
+use crate::terminal;
+use crate::video::{self, Backend};
 use std::ffi;
 use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Builds a Runtime with custom configuration values.
 ///
@@ -19,6 +24,9 @@ use std::io;
 pub struct Builder {
     title: String,
     enable_video: bool,
+    enable_audio: bool,
+    backend: Backend,
+    target_fps: Option<u32>,
 }
 
 impl Builder {
@@ -37,6 +45,9 @@ impl Builder {
         Self {
             title: "".to_string(),
             enable_video: false,
+            enable_audio: false,
+            backend: Backend::Window,
+            target_fps: None,
         }
     }
 
@@ -81,6 +92,68 @@ impl Builder {
         self
     }
 
+    /// Enable the audio driver.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kioto::runtime;
+    ///
+    /// fn main() {
+    ///     let mut runtime = runtime::Builder::new()
+    ///         .enable_audio()
+    ///         .build()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn enable_audio(&mut self) -> &mut Builder {
+        self.enable_audio = true;
+        self
+    }
+
+    /// Selects which rendering backend the video driver uses.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kioto::runtime;
+    /// use kioto::video::Backend;
+    ///
+    /// fn main() {
+    ///     let mut runtime = runtime::Builder::new()
+    ///         .enable_video()
+    ///         .backend(Backend::Terminal)
+    ///         .build()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn backend(&mut self, backend: Backend) -> &mut Builder {
+        self.backend = backend;
+        self
+    }
+
+    /// Caps the runtime loop to the given number of ticks per second.
+    ///
+    /// A `target_fps` of `0` would make the per-tick sleep duration
+    /// infinite, so it is clamped to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use kioto::runtime;
+    ///
+    /// fn main() {
+    ///     let mut runtime = runtime::Builder::new()
+    ///         .target_fps(30)
+    ///         .build()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn target_fps(&mut self, target_fps: u32) -> &mut Builder {
+        self.target_fps = Some(target_fps.max(1));
+        self
+    }
+
     /// Build a new runtime
     ///
     /// # Examples
@@ -95,21 +168,46 @@ impl Builder {
     /// }
     pub fn build(&mut self) -> io::Result<Runtime> {
         if self.enable_video {
-            let title = ffi::CString::new(self.title.clone())?;
-            let is_ready = unsafe {
-                sys::init_video(0, 0, title.as_ptr());
-                sys::is_video_ready()
-            };
+            match self.backend {
+                Backend::Window => {
+                    let title = ffi::CString::new(self.title.clone())?;
+                    let is_ready = unsafe {
+                        sys::init_video(0, 0, title.as_ptr());
+                        sys::is_video_ready()
+                    };
+
+                    if !is_ready {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "Unable to initialize video driver",
+                        ));
+                    }
+                }
+                Backend::Terminal => {
+                    video::init_terminal_backend(terminal::DEFAULT_WIDTH, terminal::DEFAULT_HEIGHT);
+                }
+            }
+        }
+
+        if self.enable_audio {
+            unsafe {
+                sys::init_audio();
+            }
 
-            if !is_ready {
+            if !unsafe { sys::is_audio_ready() } {
                 return Err(io::Error::new(
                     io::ErrorKind::Other,
-                    "Unable to initialize video driver",
+                    "Unable to initialize audio driver",
                 ));
             }
         }
 
-        Ok(Runtime { running: false })
+        Ok(Runtime {
+            running: false,
+            enable_audio: self.enable_audio,
+            target_fps: self.target_fps,
+            last_tick: None,
+        })
     }
 }
 
@@ -122,7 +220,7 @@ impl Builder {
 ///
 /// fn main() {
 ///     let mut runtime = runtime::Runtime::new();
-///     runtime.run_with(|runtime| {
+///     runtime.run_with(|runtime, _dt| {
 ///       runtime.shutdown();
 ///
 ///       Ok(())
@@ -131,6 +229,9 @@ impl Builder {
 /// ```
 pub struct Runtime {
     running: bool,
+    enable_audio: bool,
+    target_fps: Option<u32>,
+    last_tick: Option<Instant>,
 }
 
 impl Runtime {
@@ -146,10 +247,24 @@ impl Runtime {
     /// }
     /// ```
     pub fn new() -> Self {
-        Self { running: false }
+        Self {
+            running: false,
+            enable_audio: false,
+            target_fps: None,
+            last_tick: None,
+        }
     }
 
-    /// Run the runtime loop with the given callback which is called once per tick until shutdown.
+    /// Run the runtime loop with the given callback, which is called once
+    /// per tick until shutdown with the real elapsed time `dt`, in seconds,
+    /// since the previous tick. If a target FPS was set, the loop sleeps
+    /// between ticks to avoid running faster than that rate.
+    ///
+    /// Unlike `Context::run`, this is a variable-timestep loop: `callback`
+    /// plays both the update and render role in a single tick, so there is
+    /// no fixed-step accumulator or interpolation `alpha` here. Prefer
+    /// `Context`/`Delegate` when deterministic, frame-rate-independent
+    /// updates matter.
     ///
     /// # Examples
     /// ```rust
@@ -157,7 +272,7 @@ impl Runtime {
     ///
     /// fn main() {
     ///   let mut runtime = runtime::Runtime::new();
-    ///   runtime.run_with(|runtime| {
+    ///   runtime.run_with(|runtime, _dt| {
     ///     runtime.shutdown();
     ///
     ///     Ok(())
@@ -166,20 +281,34 @@ impl Runtime {
     /// ```
     pub fn run_with<F>(&mut self, callback: F) -> Result<(), io::Error>
     where
-        F: Fn(&mut Runtime) -> Result<(), io::Error>,
+        F: Fn(&mut Runtime, f32) -> Result<(), io::Error>,
     {
         self.running = true;
-        let mut result = callback(self);
+        self.last_tick = Some(Instant::now());
+        let mut result = callback(self, 0.0);
 
         while self.running && result.is_ok() {
-            unsafe {
-                sys::begin_frame();
+            video::begin_frame();
+
+            let now = Instant::now();
+            let dt = now.duration_since(self.last_tick.unwrap_or(now)).as_secs_f32();
+            self.last_tick = Some(now);
+
+            if self.enable_audio {
+                audio::update_streams();
             }
 
-            result = callback(self);
+            result = callback(self, dt);
 
-            unsafe {
-                sys::end_frame();
+            video::end_frame();
+
+            if let Some(target_fps) = self.target_fps {
+                let frame_time = Duration::from_secs_f32(1.0 / target_fps as f32);
+                let elapsed = Instant::now().duration_since(now);
+
+                if elapsed < frame_time {
+                    thread::sleep(frame_time - elapsed);
+                }
             }
         }
 
@@ -195,7 +324,7 @@ impl Runtime {
     ///
     /// fn main() {
     ///     let mut runtime = runtime::Runtime::new();
-    ///     runtime.run_with(|runtime| {
+    ///     runtime.run_with(|runtime, _dt| {
     ///         runtime.shutdown();
     ///
     ///         Ok(())
@@ -213,7 +342,13 @@ impl Drop for Runtime {
             if sys::is_video_ready() {
                 sys::close_video();
             }
+
+            if sys::is_audio_ready() {
+                sys::close_audio();
+            }
         }
+
+        video::shutdown_backend();
     }
 }
 
@@ -231,7 +366,7 @@ mod tests {
     #[test]
     pub fn run_with() {
         let mut runtime = Runtime::new();
-        let result = runtime.run_with(|runtime| {
+        let result = runtime.run_with(|runtime, _dt| {
             runtime.shutdown();
 
             Ok(())
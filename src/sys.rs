@@ -9,6 +9,70 @@ pub struct Color {
     pub a: c_uchar,
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Vector2 {
+    pub x: c_float,
+    pub y: c_float,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Rectangle {
+    pub x: c_float,
+    pub y: c_float,
+    pub width: c_float,
+    pub height: c_float,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Texture {
+    pub id: c_uint,
+    pub width: c_int,
+    pub height: c_int,
+    pub mipmaps: c_int,
+    pub format: c_int,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Font {
+    pub base_size: c_int,
+    pub glyph_count: c_int,
+    pub glyph_padding: c_int,
+    pub texture: Texture,
+    pub recs: *mut Rectangle,
+    pub glyphs: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AudioStream {
+    pub buffer: *mut c_void,
+    pub processor: *mut c_void,
+    pub sample_rate: c_uint,
+    pub sample_size: c_uint,
+    pub channels: c_uint,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sound {
+    pub stream: AudioStream,
+    pub frame_count: c_uint,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Music {
+    pub stream: AudioStream,
+    pub frame_count: c_uint,
+    pub looping: bool,
+    pub ctx_type: c_int,
+    pub ctx_data: *mut c_void,
+}
+
 #[link(name = "raylib")]
 extern "C" {
     #[link_name = "InitWindow"]
@@ -39,6 +103,89 @@ extern "C" {
     pub fn end_frame();
 }
 
+#[link(name = "raylib")]
+extern "C" {
+    #[link_name = "LoadTexture"]
+    pub fn load_texture(path: *const c_char) -> Texture;
+
+    #[link_name = "UnloadTexture"]
+    pub fn unload_texture(texture: Texture);
+
+    #[link_name = "DrawTextureEx"]
+    pub fn draw_texture_ex(
+        texture: Texture,
+        position: Vector2,
+        rotation: c_float,
+        scale: c_float,
+        tint: Color,
+    );
+}
+
+#[link(name = "raylib")]
+extern "C" {
+    #[link_name = "LoadFontEx"]
+    pub fn load_font_ex(
+        path: *const c_char,
+        size: c_int,
+        chars: *mut c_int,
+        glyph_count: c_int,
+    ) -> Font;
+
+    #[link_name = "UnloadFont"]
+    pub fn unload_font(font: Font);
+
+    #[link_name = "DrawTextEx"]
+    pub fn draw_text_ex(
+        font: Font,
+        text: *const c_char,
+        position: Vector2,
+        size: c_float,
+        spacing: c_float,
+        tint: Color,
+    );
+
+    #[link_name = "MeasureTextEx"]
+    pub fn measure_text_ex(
+        font: Font,
+        text: *const c_char,
+        size: c_float,
+        spacing: c_float,
+    ) -> Vector2;
+}
+
+#[link(name = "raylib")]
+extern "C" {
+    #[link_name = "InitAudioDevice"]
+    pub fn init_audio();
+
+    #[link_name = "CloseAudioDevice"]
+    pub fn close_audio();
+
+    #[link_name = "IsAudioDeviceReady"]
+    pub fn is_audio_ready() -> bool;
+
+    #[link_name = "LoadSound"]
+    pub fn load_sound(path: *const c_char) -> Sound;
+
+    #[link_name = "UnloadSound"]
+    pub fn unload_sound(sound: Sound);
+
+    #[link_name = "PlaySound"]
+    pub fn play_sound(sound: Sound);
+
+    #[link_name = "LoadMusicStream"]
+    pub fn load_music_stream(path: *const c_char) -> Music;
+
+    #[link_name = "UnloadMusicStream"]
+    pub fn unload_music_stream(music: Music);
+
+    #[link_name = "PlayMusicStream"]
+    pub fn play_music_stream(music: Music);
+
+    #[link_name = "UpdateMusicStream"]
+    pub fn update_music_stream(music: Music);
+}
+
 #[link(name="raylib")]
 extern "C" {
     #[link_name="IsKeyPressed"]
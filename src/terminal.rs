@@ -0,0 +1,208 @@
+//! Headless terminal rendering backend using ANSI 24-bit truecolor.
+//!
+//! Instead of opening a raylib window, frames are rasterized into an
+//! in-memory RGBA framebuffer and flushed to stdout as ANSI escape codes,
+//! packing two vertically-adjacent pixels into a single character cell with
+//! the upper-half-block glyph `▀` (foreground = top pixel, background =
+//! bottom pixel). This lets a kioto app run over SSH with no display.
+
+use crate::video::Color;
+use std::io::{self, Write};
+
+/// The default framebuffer width, in pixels, used when no size is given.
+pub const DEFAULT_WIDTH: u32 = 160;
+
+/// The default framebuffer height, in pixels, used when no size is given.
+pub const DEFAULT_HEIGHT: u32 = 96;
+
+/// Returns true if the terminal has announced 24-bit truecolor support.
+pub fn supports_truecolor() -> bool {
+    match std::env::var("COLORTERM") {
+        Ok(value) => value == "truecolor" || value == "24bit",
+        Err(_) => false,
+    }
+}
+
+/// Quantizes a color to the nearest entry in the 256-color xterm cube, for
+/// terminals that lack truecolor support.
+fn to_256_color(color: Color) -> u8 {
+    // The cube's six levels are 0, 95, 135, 175, 215 and 255; pick the
+    // closest one by splitting at the midpoints between them.
+    let level = |channel: u8| -> u8 {
+        if channel < 48 {
+            0
+        } else if channel < 115 {
+            1
+        } else {
+            (channel - 35) / 40
+        }
+    };
+
+    16 + 36 * level(color.r) + 6 * level(color.g) + level(color.b)
+}
+
+fn foreground_escape(color: Color, truecolor: bool) -> String {
+    if truecolor {
+        format!("\x1b[38;2;{};{};{}m", color.r, color.g, color.b)
+    } else {
+        format!("\x1b[38;5;{}m", to_256_color(color))
+    }
+}
+
+fn background_escape(color: Color, truecolor: bool) -> String {
+    if truecolor {
+        format!("\x1b[48;2;{};{};{}m", color.r, color.g, color.b)
+    } else {
+        format!("\x1b[48;5;{}m", to_256_color(color))
+    }
+}
+
+/// An in-memory RGBA framebuffer that `video::draw_*` calls rasterize into
+/// when the terminal backend is active.
+pub struct Framebuffer {
+    width: u32,
+    height: u32,
+    pixels: Vec<Color>,
+    previous: Vec<Color>,
+    truecolor: bool,
+}
+
+impl Framebuffer {
+    /// Creates a framebuffer of the given pixel dimensions, cleared to black.
+    pub fn new(width: u32, height: u32) -> Framebuffer {
+        let pixels = vec![Color::BLACK; (width * height) as usize];
+
+        Framebuffer {
+            width,
+            height,
+            previous: Vec::new(),
+            pixels,
+            truecolor: supports_truecolor(),
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return None;
+        }
+
+        Some(y as usize * self.width as usize + x as usize)
+    }
+
+    /// Sets a single pixel, clipping anything outside the framebuffer.
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: Color) {
+        if let Some(index) = self.index(x, y) {
+            self.pixels[index] = color;
+        }
+    }
+
+    /// Clears the whole framebuffer to `color`.
+    pub fn clear(&mut self, color: Color) {
+        self.pixels.fill(color);
+    }
+
+    /// Fills an axis-aligned rectangle.
+    pub fn fill_rectangle(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        for dy in 0..height {
+            for dx in 0..width {
+                self.set_pixel(x + dx, y + dy, color);
+            }
+        }
+    }
+
+    /// Draws a line using Bresenham's algorithm.
+    pub fn draw_line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, color: Color) {
+        let (mut x, mut y) = (x1, y1);
+        let dx = (x2 - x1).abs();
+        let dy = -(y2 - y1).abs();
+        let sx = if x1 < x2 { 1 } else { -1 };
+        let sy = if y1 < y2 { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        loop {
+            self.set_pixel(x, y, color);
+
+            if x == x2 && y == y2 {
+                break;
+            }
+
+            let doubled = 2 * error;
+
+            if doubled >= dy {
+                error += dy;
+                x += sx;
+            }
+
+            if doubled <= dx {
+                error += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Fills a circle of the given radius centered at `(x, y)`.
+    pub fn fill_circle(&mut self, x: i32, y: i32, radius: f32, color: Color) {
+        let radius = radius.round() as i32;
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if dx * dx + dy * dy <= radius * radius {
+                    self.set_pixel(x + dx, y + dy, color);
+                }
+            }
+        }
+    }
+
+    /// Flushes the framebuffer to stdout, rewriting only changed cells.
+    pub fn present(&mut self) -> io::Result<()> {
+        let mut out = io::stdout();
+        let mut buffer = String::from("\x1b[H");
+        let rows = (self.height + 1) / 2;
+
+        for row in 0..rows {
+            if row > 0 {
+                buffer.push_str("\r\n");
+            }
+
+            let top = row * 2;
+            let bottom = top + 1;
+
+            for column in 0..self.width {
+                let top_color = self.pixels[(top * self.width + column) as usize];
+                let bottom_color = if bottom < self.height {
+                    self.pixels[(bottom * self.width + column) as usize]
+                } else {
+                    Color::BLACK
+                };
+
+                let pixel_changed = |index: usize, color: Color| -> bool {
+                    self.previous.is_empty()
+                        || self.previous[index].r != color.r
+                        || self.previous[index].g != color.g
+                        || self.previous[index].b != color.b
+                };
+
+                let changed = pixel_changed((top * self.width + column) as usize, top_color)
+                    || (bottom < self.height
+                        && pixel_changed((bottom * self.width + column) as usize, bottom_color));
+
+                if changed {
+                    buffer.push_str(&foreground_escape(top_color, self.truecolor));
+                    buffer.push_str(&background_escape(bottom_color, self.truecolor));
+                    buffer.push('▀');
+                } else {
+                    buffer.push_str("\x1b[C");
+                }
+            }
+
+            buffer.push_str("\x1b[0m");
+        }
+
+        out.write_all(buffer.as_bytes())?;
+        out.flush()?;
+
+        self.previous = self.pixels.clone();
+
+        Ok(())
+    }
+}
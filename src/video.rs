@@ -2,10 +2,107 @@
 //!
 
 use crate::sys;
+use crate::terminal::Framebuffer;
 use std::ffi;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
 
 pub type Color = sys::Color;
 
+/// Which rendering backend `video::draw_*` calls are sent to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::context;
+/// use kioto::video::Backend;
+///
+/// let context = context::Builder::new()
+///     .enable_video()
+///     .backend(Backend::Terminal)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backend {
+    /// Draw into a raylib window.
+    Window,
+    /// Rasterize into an in-memory framebuffer and print it to stdout.
+    Terminal,
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Window
+    }
+}
+
+static BACKEND: Mutex<Backend> = Mutex::new(Backend::Window);
+static FRAMEBUFFER: Mutex<Option<Framebuffer>> = Mutex::new(None);
+
+/// Switches the active backend to the headless terminal renderer, sizing
+/// its framebuffer to `width` by `height` pixels.
+pub(crate) fn init_terminal_backend(width: u32, height: u32) {
+    *FRAMEBUFFER.lock().unwrap() = Some(Framebuffer::new(width, height));
+    *BACKEND.lock().unwrap() = Backend::Terminal;
+    print!("\x1b[?25l");
+}
+
+/// Tears down whichever backend is active, restoring the terminal cursor.
+pub(crate) fn shutdown_backend() {
+    if *BACKEND.lock().unwrap() == Backend::Terminal {
+        print!("\x1b[?25h");
+    }
+
+    *FRAMEBUFFER.lock().unwrap() = None;
+    *BACKEND.lock().unwrap() = Backend::Window;
+}
+
+fn with_framebuffer<F: FnOnce(&mut Framebuffer)>(f: F) -> bool {
+    let mut guard = FRAMEBUFFER.lock().unwrap();
+
+    match guard.as_mut() {
+        Some(framebuffer) => {
+            f(framebuffer);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns true if the headless terminal backend is currently active.
+///
+/// Textures, sprites, and custom fonts require a GPU context that the
+/// terminal backend does not provide, so callers use this to no-op rather
+/// than invoke raylib without a window.
+fn is_terminal_backend() -> bool {
+    *BACKEND.lock().unwrap() == Backend::Terminal
+}
+
+/// Begins a frame, readying whichever backend is active.
+pub(crate) fn begin_frame() {
+    if *BACKEND.lock().unwrap() == Backend::Window {
+        unsafe {
+            sys::begin_frame();
+        }
+    }
+}
+
+/// Ends a frame, presenting it through whichever backend is active.
+pub(crate) fn end_frame() {
+    if *BACKEND.lock().unwrap() == Backend::Window {
+        unsafe {
+            sys::end_frame();
+        }
+        return;
+    }
+
+    with_framebuffer(|framebuffer| {
+        let _ = framebuffer.present();
+    });
+}
+
 impl Color {
     pub const GRAY: Color = Color {
         r: 130,
@@ -157,6 +254,185 @@ impl Color {
         b: 245,
         a: 255,
     };
+
+    /// Parses a `"#RRGGBB"` or `"#RRGGBBAA"` hex string into a color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kioto::video::Color;
+    ///
+    /// let color = Color::from_hex("#FF0000").unwrap();
+    /// assert_eq!(color.r, 255);
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !hex.is_ascii() {
+            return None;
+        }
+
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+        match hex.len() {
+            6 => Some(Color {
+                r: channel(0)?,
+                g: channel(2)?,
+                b: channel(4)?,
+                a: 255,
+            }),
+            8 => Some(Color {
+                r: channel(0)?,
+                g: channel(2)?,
+                b: channel(4)?,
+                a: channel(6)?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Builds a color from hue (0-360), saturation (0-1), and value (0-1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kioto::video::Color;
+    ///
+    /// let red = Color::from_hsv(0.0, 1.0, 1.0);
+    /// assert_eq!(red.r, 255);
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Color {
+        let h = h.rem_euclid(360.0);
+        let c = v * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r, g, b) = if h < 60.0 {
+            (c, x, 0.0)
+        } else if h < 120.0 {
+            (x, c, 0.0)
+        } else if h < 180.0 {
+            (0.0, c, x)
+        } else if h < 240.0 {
+            (0.0, x, c)
+        } else if h < 300.0 {
+            (x, 0.0, c)
+        } else {
+            (c, 0.0, x)
+        };
+
+        Color {
+            r: (((r + m) * 255.0).round()) as u8,
+            g: (((g + m) * 255.0).round()) as u8,
+            b: (((b + m) * 255.0).round()) as u8,
+            a: 255,
+        }
+    }
+
+    /// Converts this color to hue (0-360), saturation (0-1), and value (0-1).
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * (((g - b) / delta).rem_euclid(6.0))
+        } else if max == g {
+            60.0 * (((b - r) / delta) + 2.0)
+        } else {
+            60.0 * (((r - g) / delta) + 4.0)
+        };
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        let v = max;
+
+        (h, s, v)
+    }
+
+    /// Returns a copy of this color with the alpha channel replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kioto::video::Color;
+    ///
+    /// let faded = Color::WHITE.with_alpha(128);
+    /// assert_eq!(faded.a, 128);
+    /// ```
+    pub fn with_alpha(self, a: u8) -> Color {
+        Color { a, ..self }
+    }
+
+    /// Linearly interpolates between this color and `other` by `t` (0-1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kioto::video::Color;
+    ///
+    /// let mid = Color::BLACK.lerp(Color::WHITE, 0.5);
+    /// assert_eq!(mid.r, 128);
+    /// ```
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let lerp_channel = |a: u8, b: u8| -> u8 {
+            (a as f32 + (b as f32 - a as f32) * t).round() as u8
+        };
+
+        Color {
+            r: lerp_channel(self.r, other.r),
+            g: lerp_channel(self.g, other.g),
+            b: lerp_channel(self.b, other.b),
+            a: lerp_channel(self.a, other.a),
+        }
+    }
+}
+
+/// The range that a color's channel values span, mirroring video signal conventions.
+///
+/// # Examples
+///
+/// ```
+/// use kioto::video::ColorRange;
+///
+/// let normalized = ColorRange::Limited.normalize(16);
+/// assert_eq!(normalized, 0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColorRange {
+    /// The full 0-255 channel range.
+    Full,
+    /// The limited "TV" 16-235 channel range used by many video sources.
+    Limited,
+}
+
+impl ColorRange {
+    /// Rescales a channel value sampled in this range into the full 0-255 range.
+    pub fn normalize(self, value: u8) -> u8 {
+        match self {
+            ColorRange::Full => value,
+            ColorRange::Limited => {
+                let scaled = (value as f32 - 16.0) / (235.0 - 16.0) * 255.0;
+                scaled.round().clamp(0.0, 255.0) as u8
+            }
+        }
+    }
+
+    /// Rescales a full-range (0-255) channel value into this range.
+    pub fn expand(self, value: u8) -> u8 {
+        match self {
+            ColorRange::Full => value,
+            ColorRange::Limited => {
+                let scaled = 16.0 + (value as f32 / 255.0) * (235.0 - 16.0);
+                scaled.round().clamp(0.0, 255.0) as u8
+            }
+        }
+    }
 }
 
 /// Clear the screen to the given background color.
@@ -169,6 +445,10 @@ impl Color {
 /// video::clear_background(video::Color::BLACK);
 /// ```
 pub fn clear_background(color: Color) {
+    if with_framebuffer(|framebuffer| framebuffer.clear(color)) {
+        return;
+    }
+
     unsafe {
         sys::clear_background(color);
     }
@@ -184,6 +464,10 @@ pub fn clear_background(color: Color) {
 /// video::draw_line(0, 0, 100, 100, video::Color::WHITE);
 /// ```
 pub fn draw_line(x1: i32, y1: i32, x2: i32, y2: i32, color: Color) {
+    if with_framebuffer(|framebuffer| framebuffer.draw_line(x1, y1, x2, y2, color)) {
+        return;
+    }
+
     unsafe {
         sys::draw_line(x1, y1, x2, y2, color);
     }
@@ -199,6 +483,10 @@ pub fn draw_line(x1: i32, y1: i32, x2: i32, y2: i32, color: Color) {
 /// video::draw_circle(0, 0, 100.0, video::Color::WHITE);
 /// ```
 pub fn draw_circle(x: i32, y: i32, radius: f32, color: Color) {
+    if with_framebuffer(|framebuffer| framebuffer.fill_circle(x, y, radius, color)) {
+        return;
+    }
+
     unsafe {
         sys::draw_circle(x, y, radius, color);
     }
@@ -214,6 +502,10 @@ pub fn draw_circle(x: i32, y: i32, radius: f32, color: Color) {
 /// video::draw_rectangle(0, 0, 100, 100, video::Color::WHITE);
 /// ```
 pub fn draw_rectangle(x: i32, y: i32, width: i32, height: i32, color: Color) {
+    if with_framebuffer(|framebuffer| framebuffer.fill_rectangle(x, y, width, height, color)) {
+        return;
+    }
+
     unsafe {
         sys::draw_rectangle(x, y, width, height, color);
     }
@@ -229,9 +521,332 @@ pub fn draw_rectangle(x: i32, y: i32, width: i32, height: i32, color: Color) {
 /// video::draw_text("Hello, world!", 0, 0, 32, video::Color::WHITE);
 /// ```
 pub fn draw_text(text: &str, x: i32, y: i32, size: i32, color: Color) {
+    if with_framebuffer(|framebuffer| draw_text_blocks(framebuffer, text, x, y, size, color)) {
+        return;
+    }
+
     let text = ffi::CString::new(text).unwrap();
 
     unsafe {
         sys::draw_text(text.as_ptr(), x, y, size, color);
     }
 }
+
+/// The terminal backend has no glyph rasterizer, so each character is
+/// approximated by a solid block advancing at a fixed width.
+fn draw_text_blocks(framebuffer: &mut Framebuffer, text: &str, x: i32, y: i32, size: i32, color: Color) {
+    let advance = (size as f32 * 0.6).round() as i32;
+
+    for (index, ch) in text.chars().enumerate() {
+        if ch == ' ' {
+            continue;
+        }
+
+        framebuffer.fill_rectangle(x + index as i32 * advance, y, advance.max(1), size, color);
+    }
+}
+
+/// How a string of text is rendered onto the framebuffer.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::video;
+///
+/// let mode = video::TextMode::Shaded {
+///     foreground: video::Color::WHITE,
+///     background: video::Color::BLACK,
+/// };
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub enum TextMode {
+    /// Renders the text in a single solid color.
+    Solid(Color),
+    /// Renders the text over a filled rectangle sized to fit it exactly.
+    Shaded { foreground: Color, background: Color },
+    /// Renders the text in a color with the given alpha applied.
+    Blended(Color, u8),
+}
+
+/// A custom TTF font loaded at a fixed size.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::video;
+///
+/// let font = video::Font::load("assets/roboto.ttf", 32).unwrap();
+/// ```
+pub struct Font {
+    raw: sys::Font,
+}
+
+impl Font {
+    /// Loads a TTF font file from disk, rasterized at `size` pixels.
+    pub fn load<P>(path: P, size: i32) -> io::Result<Font>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_str().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Font path is not valid UTF-8")
+        })?;
+        let path = ffi::CString::new(path)?;
+
+        let raw = unsafe {
+            sys::load_font_ex(path.as_ptr(), size, std::ptr::null_mut(), 0)
+        };
+
+        if raw.texture.id == 0 {
+            return Err(io::Error::new(io::ErrorKind::Other, "Unable to load font"));
+        }
+
+        Ok(Font { raw })
+    }
+
+    fn measure(&self, text: &ffi::CString, size: f32) -> sys::Vector2 {
+        unsafe { sys::measure_text_ex(self.raw, text.as_ptr(), size, 0.0) }
+    }
+}
+
+impl Drop for Font {
+    fn drop(&mut self) {
+        unsafe {
+            sys::unload_font(self.raw);
+        }
+    }
+}
+
+/// Draws `text` with a custom font and the given text mode.
+///
+/// Custom fonts require a raylib GPU context, so this is a no-op under
+/// `Backend::Terminal`; use `draw_text` there instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::video;
+///
+/// let font = video::Font::load("assets/roboto.ttf", 32).unwrap();
+///
+/// video::draw_text_ex(&font, "Hello, world!", 0, 0, 32.0, video::TextMode::Solid(video::Color::WHITE));
+/// ```
+pub fn draw_text_ex(font: &Font, text: &str, x: i32, y: i32, size: f32, mode: TextMode) {
+    if is_terminal_backend() {
+        return;
+    }
+
+    let cstr = ffi::CString::new(text).unwrap();
+
+    if let TextMode::Shaded { background, .. } = mode {
+        let measured = font.measure(&cstr, size);
+
+        unsafe {
+            sys::draw_rectangle(x, y, measured.x as i32, measured.y as i32, background);
+        }
+    }
+
+    let tint = match mode {
+        TextMode::Solid(color) => color,
+        TextMode::Shaded { foreground, .. } => foreground,
+        TextMode::Blended(color, alpha) => color.with_alpha(alpha),
+    };
+
+    unsafe {
+        sys::draw_text_ex(
+            font.raw,
+            cstr.as_ptr(),
+            sys::Vector2 {
+                x: x as f32,
+                y: y as f32,
+            },
+            size,
+            0.0,
+            tint,
+        );
+    }
+}
+
+/// An image loaded into GPU memory, ready to be drawn.
+///
+/// The underlying GPU texture is unloaded when the `Texture` is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::video;
+///
+/// let texture = video::Texture::load("assets/player.png").unwrap();
+/// ```
+pub struct Texture {
+    raw: sys::Texture,
+}
+
+impl Texture {
+    /// Loads an image file from disk into a GPU texture.
+    pub fn load<P>(path: P) -> io::Result<Texture>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_str().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Texture path is not valid UTF-8")
+        })?;
+        let path = ffi::CString::new(path)?;
+
+        let raw = unsafe { sys::load_texture(path.as_ptr()) };
+
+        if raw.id == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Unable to load texture",
+            ));
+        }
+
+        Ok(Texture { raw })
+    }
+
+    /// The width of the texture in pixels.
+    pub fn width(&self) -> i32 {
+        self.raw.width
+    }
+
+    /// The height of the texture in pixels.
+    pub fn height(&self) -> i32 {
+        self.raw.height
+    }
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        unsafe {
+            sys::unload_texture(self.raw);
+        }
+    }
+}
+
+/// A drawable image with a position, rotation, scale, and tint.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kioto::video;
+///
+/// let mut sprite = video::Sprite::new("assets/player.png", 0.0, 0.0).unwrap();
+/// sprite.set_angle(90.0);
+/// sprite.draw();
+/// ```
+pub struct Sprite {
+    texture: Texture,
+    x: f32,
+    y: f32,
+    angle: f32,
+    scale: f32,
+    tint: Color,
+}
+
+impl Sprite {
+    /// Loads the image at `path` and places it at `(x, y)`.
+    pub fn new<P>(path: P, x: f32, y: f32) -> io::Result<Sprite>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Sprite {
+            texture: Texture::load(path)?,
+            x,
+            y,
+            angle: 0.0,
+            scale: 1.0,
+            tint: Color::WHITE,
+        })
+    }
+
+    /// Sets the rotation angle, in degrees.
+    pub fn set_angle(&mut self, angle: f32) {
+        self.angle = angle;
+    }
+
+    /// Sets the position of the sprite.
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /// Sets the uniform scale applied when drawing the sprite.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    /// Sets the color tint applied when drawing the sprite.
+    pub fn set_tint(&mut self, tint: Color) {
+        self.tint = tint;
+    }
+
+    /// Draws the sprite at its current position, angle, scale, and tint.
+    ///
+    /// Sprites require a raylib GPU texture, so this is a no-op under
+    /// `Backend::Terminal`.
+    pub fn draw(&self) {
+        if is_terminal_backend() {
+            return;
+        }
+
+        unsafe {
+            sys::draw_texture_ex(
+                self.texture.raw,
+                sys::Vector2 {
+                    x: self.x,
+                    y: self.y,
+                },
+                self.angle,
+                self.scale,
+                self.tint,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn from_hex_parses_rgb_and_rgba() {
+        let rgb = Color::from_hex("#FF0000").unwrap();
+        assert_eq!((rgb.r, rgb.g, rgb.b, rgb.a), (255, 0, 0, 255));
+
+        let rgba = Color::from_hex("00FF0080").unwrap();
+        assert_eq!((rgba.r, rgba.g, rgba.b, rgba.a), (0, 255, 0, 128));
+    }
+
+    #[test]
+    pub fn from_hex_rejects_bad_input() {
+        assert!(Color::from_hex("#FF00").is_none());
+        assert!(Color::from_hex("#ßßßßßß").is_none());
+        assert!(Color::from_hex("#12😀").is_none());
+    }
+
+    #[test]
+    pub fn from_hsv_and_to_hsv_round_trip() {
+        let red = Color::from_hsv(0.0, 1.0, 1.0);
+        assert_eq!((red.r, red.g, red.b), (255, 0, 0));
+
+        let (h, s, v) = red.to_hsv();
+        assert_eq!((h.round() as i32, s, v), (0, 1.0, 1.0));
+    }
+
+    #[test]
+    pub fn to_hsv_of_black_has_zero_saturation_and_value() {
+        let (h, s, v) = Color::BLACK.to_hsv();
+        assert_eq!((h, s, v), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    pub fn color_range_normalize_and_expand_round_trip() {
+        assert_eq!(ColorRange::Limited.normalize(16), 0);
+        assert_eq!(ColorRange::Limited.normalize(235), 255);
+        assert_eq!(ColorRange::Limited.expand(0), 16);
+        assert_eq!(ColorRange::Limited.expand(255), 235);
+
+        assert_eq!(ColorRange::Full.normalize(42), 42);
+        assert_eq!(ColorRange::Full.expand(42), 42);
+    }
+}